@@ -1,5 +1,6 @@
 use std::os::raw::{c_int, c_double, c_void};
 use std::ptr;
+use std::sync::Arc;
 
 use ffi;
 use base::*;
@@ -8,8 +9,7 @@ extern "C" fn write_wrapper<W>(raw_out: *mut ffi::SoundIoOutStream, min: c_int,
     where W: Fn(OutStream, i32, i32)
 {
     let out = OutStream::new(raw_out);
-    let callbacks_ptr = unsafe { (*out.stream).userdata as *const Box<OutStreamCallbacks> };
-    let callbacks: &Box<OutStreamCallbacks> = unsafe { &*callbacks_ptr };
+    let callbacks = unsafe { &*((*raw_out).userdata as *const OutStreamCallbacks) };
     callbacks.write.as_ref().map(|ref f| f(out, min as i32, max as i32));
 }
 
@@ -17,8 +17,7 @@ extern "C" fn underflow_wrapper<U>(raw_out: *mut ffi::SoundIoOutStream)
     where U: Fn(OutStream)
 {
     let out = OutStream::new(raw_out);
-    let callbacks_ptr = unsafe { (*out.stream).userdata as *const Box<OutStreamCallbacks> };
-    let callbacks: &Box<OutStreamCallbacks> = unsafe { &*callbacks_ptr };
+    let callbacks = unsafe { &*((*raw_out).userdata as *const OutStreamCallbacks) };
     callbacks.underflow.as_ref().map(|ref f| f(out));
 }
 
@@ -26,8 +25,7 @@ extern "C" fn error_wrapper<E>(raw_out: *mut ffi::SoundIoOutStream, error: ffi::
     where E: Fn(OutStream, ffi::SioError)
 {
     let out = OutStream::new(raw_out);
-    let callbacks_ptr = unsafe { (*out.stream).userdata as *const Box<OutStreamCallbacks> };
-    let callbacks: &Box<OutStreamCallbacks> = unsafe { &*callbacks_ptr };
+    let callbacks = unsafe { &*((*raw_out).userdata as *const OutStreamCallbacks) };
     callbacks.error.as_ref().map(|ref f| f(out, error));
 }
 
@@ -45,20 +43,63 @@ impl<'a> Default for OutStreamCallbacks<'a> {
         }
     }
 }
-impl<'a> Drop for OutStreamCallbacks<'a> {
-    fn drop(&mut self) {}
+
+/// A sample type corresponding to one of libsoundio's `SioFormat` variants.
+pub trait Sample: Copy {
+    const FORMAT: ffi::SioFormat;
+
+    // writes self to addr in the device's native endianness
+    unsafe fn write_to(self, addr: *mut u8);
+}
+
+impl Sample for i16 {
+    const FORMAT: ffi::SioFormat = ffi::SioFormat::S16;
+    unsafe fn write_to(self, addr: *mut u8) {
+        ptr::write(addr as *mut Self, self);
+    }
 }
 
+impl Sample for u16 {
+    const FORMAT: ffi::SioFormat = ffi::SioFormat::U16;
+    unsafe fn write_to(self, addr: *mut u8) {
+        ptr::write(addr as *mut Self, self);
+    }
+}
+
+impl Sample for i32 {
+    const FORMAT: ffi::SioFormat = ffi::SioFormat::S32;
+    unsafe fn write_to(self, addr: *mut u8) {
+        ptr::write(addr as *mut Self, self);
+    }
+}
+
+impl Sample for f32 {
+    const FORMAT: ffi::SioFormat = ffi::SioFormat::Float32;
+    unsafe fn write_to(self, addr: *mut u8) {
+        ptr::write(addr as *mut Self, self);
+    }
+}
+
+impl Sample for f64 {
+    const FORMAT: ffi::SioFormat = ffi::SioFormat::Float64;
+    unsafe fn write_to(self, addr: *mut u8) {
+        ptr::write(addr as *mut Self, self);
+    }
+}
+
+/// A non-owning view of an output stream, passed into callbacks. Not
+/// `Clone`/`Copy` - `OutStreamHandle`'s `Deref` must only ever hand out
+/// `&OutStream<'a>` borrows tied to the handle's lifetime, never an owned
+/// copy that could outlive the handle's `Drop` (which destroys the stream).
 pub struct OutStream<'a> {
     stream: *mut ffi::SoundIoOutStream,
-    callbacks: Box<OutStreamCallbacks<'a>>,
+    _marker: ::std::marker::PhantomData<&'a ()>,
 }
 impl<'a> OutStream<'a> {
-    pub fn new(raw_stream: *mut ffi::SoundIoOutStream) -> Self {
-        let callbacks = Box::new(OutStreamCallbacks::default());
+    fn new(raw_stream: *mut ffi::SoundIoOutStream) -> Self {
         OutStream {
             stream: raw_stream,
-            callbacks: callbacks,
+            _marker: ::std::marker::PhantomData,
         }
     }
 
@@ -76,50 +117,14 @@ impl<'a> OutStream<'a> {
         }
     }
 
-    pub fn register_write_callback<W>(&mut self, callback: Box<W>)
-        where W: Fn(OutStream, i32, i32) + 'a
-    {
-        // stored box reference to callback closure
-        self.callbacks.write = Some(callback);
-        unsafe {
-            // register wrapper for write_callback
-            (*self.stream).write_callback = Some(write_wrapper::<W>);
-            // store reference to callbacks struct in userdata pointer
-            (*self.stream).userdata =
-                &self.callbacks as *const Box<OutStreamCallbacks> as *mut c_void
-        }
-    }
-
-    pub fn register_underflow_callback<U>(&mut self, callback: Box<U>)
-        where U: Fn(OutStream) + 'a
-    {
-        self.callbacks.underflow = Some(callback);
-        unsafe {
-            // register wrapper for write_callback
-            (*self.stream).underflow_callback = Some(underflow_wrapper::<U>);
-            // store reference to callbacks struct in userdata pointer
-            (*self.stream).userdata =
-                &self.callbacks as *const Box<OutStreamCallbacks> as *mut c_void
-        }
-    }
-
-    pub fn register_error_callback<E>(&mut self, callback: Box<E>)
-        where E: Fn(OutStream, ffi::SioError) + 'a
-    {
-        self.callbacks.error = Some(callback);
-        unsafe {
-            // register wrapper for write_callback
-            (*self.stream).error_callback = Some(error_wrapper::<E>);
-            // store reference to callbacks struct in userdata pointer
-            (*self.stream).userdata =
-                &self.callbacks as *const Box<OutStreamCallbacks> as *mut c_void
-        }
-    }
-
-    pub fn write_stream(&self,
+    pub fn write_stream<S: Sample>(&mut self,
                         min_frame_count: i32,
-                        buffers: &Vec<Vec<f32>>)
+                        buffers: &Vec<Vec<S>>)
                         -> Result<i32, ffi::SioError> {
+        if S::FORMAT != try!(self.current_format()) {
+            return Err(ffi::SioError::Invalid);
+        }
+
         let channel_count = self.get_layout().channel_count();
         // check if buffer contains frames for all channels
         if buffers.len() < channel_count as usize {
@@ -129,29 +134,33 @@ impl<'a> OutStream<'a> {
         if !buffers.iter().map(|c| c.len()).all(|l| l >= min_frame_count as usize) {
             return Err(ffi::SioError::Invalid);
         }
+        // require every channel buffer to have the same length, since the
+        // write loop below indexes every channel up to actual_frame_count
+        if !buffers.iter().all(|c| c.len() == buffers[0].len()) {
+            return Err(ffi::SioError::Invalid);
+        }
 
-        // assuming that every channel buffer has the same length
         let mut frame_count = buffers[0].len() as c_int;
         let mut raw_areas: *mut ffi::SoundIoChannelArea = ptr::null_mut();
-        let actual_frame_count = try!(self.begin_write(&mut raw_areas, &frame_count));
+        let actual_frame_count = try!(self.begin_write(&mut raw_areas, &mut frame_count));
         let areas = unsafe { ::std::slice::from_raw_parts_mut(raw_areas, channel_count as usize) };
         for idx in 0..actual_frame_count as usize {
             for channel in 0..channel_count as usize {
                 let area = areas[channel];
-                let addr = (area.ptr as usize + area.step as usize * idx) as *mut f32;
-                unsafe { *addr = buffers[channel][idx] }
+                let addr = (area.ptr as usize + area.step as usize * idx) as *mut u8;
+                unsafe { buffers[channel][idx].write_to(addr) }
             }
         }
         self.end_write().map_or(Ok(actual_frame_count), |err| Err(err))
     }
 
-    pub fn begin_write(&self,
+    pub fn begin_write(&mut self,
                        areas: *mut *mut ffi::SoundIoChannelArea,
                        frame_count: *mut c_int)
-                       -> Option<ffi::SioError> {
+                       -> Result<i32, ffi::SioError> {
         match unsafe { ffi::soundio_outstream_begin_write(self.stream, areas, frame_count) } {
-            ffi::SioError::None => None,
-            err @ _ => Some(err),
+            ffi::SioError::None => Ok(unsafe { *frame_count } as i32),
+            err @ _ => Err(err),
         }
     }
 
@@ -162,6 +171,29 @@ impl<'a> OutStream<'a> {
         }
     }
 
+    /// Begins a write directly into the device's buffer; `*frame_count` is
+    /// updated to the actual number of frames the caller may write.
+    pub fn write_guard<'s>(&'s mut self, frame_count: &mut i32) -> Result<WriteGuard<'s>, ffi::SioError> {
+        let channel_count = self.get_layout().channel_count() as usize;
+        let mut raw_areas: *mut ffi::SoundIoChannelArea = ptr::null_mut();
+        let mut raw_frame_count = *frame_count as c_int;
+        match unsafe {
+            ffi::soundio_outstream_begin_write(self.stream, &mut raw_areas, &mut raw_frame_count)
+        } {
+            ffi::SioError::None => {
+                *frame_count = raw_frame_count as i32;
+                Ok(WriteGuard {
+                    stream: self.stream,
+                    areas: raw_areas,
+                    channel_count: channel_count,
+                    frame_count: raw_frame_count as i32,
+                    _marker: ::std::marker::PhantomData,
+                })
+            }
+            err @ _ => Err(err),
+        }
+    }
+
     pub fn clear_buffer(&self) -> Option<ffi::SioError> {
         match unsafe { ffi::soundio_outstream_clear_buffer(self.stream) } {
             ffi::SioError::None => None,
@@ -212,14 +244,521 @@ impl<'a> OutStream<'a> {
         dev
     }
 
-    pub fn destroy(&self) {
-        unsafe { ffi::soundio_outstream_destroy(self.stream) }
+}
+
+/// The owning handle for an output stream; destroys it on drop.
+pub struct OutStreamHandle<'a> {
+    view: OutStream<'a>,
+    callbacks: Box<OutStreamCallbacks<'a>>,
+}
+impl<'a> OutStreamHandle<'a> {
+    pub fn new(raw_stream: *mut ffi::SoundIoOutStream) -> Self {
+        let mut callbacks = Box::new(OutStreamCallbacks::default());
+        unsafe {
+            (*raw_stream).userdata = &mut *callbacks as *mut OutStreamCallbacks as *mut c_void;
+        }
+        OutStreamHandle {
+            view: OutStream::new(raw_stream),
+            callbacks: callbacks,
+        }
+    }
+
+    pub fn register_write_callback<W>(&mut self, callback: Box<W>)
+        where W: Fn(OutStream, i32, i32) + 'a
+    {
+        self.callbacks.write = Some(callback);
+        unsafe {
+            (*self.view.stream).write_callback = Some(write_wrapper::<W>);
+        }
+    }
+
+    pub fn register_underflow_callback<U>(&mut self, callback: Box<U>)
+        where U: Fn(OutStream) + 'a
+    {
+        self.callbacks.underflow = Some(callback);
+        unsafe {
+            (*self.view.stream).underflow_callback = Some(underflow_wrapper::<U>);
+        }
+    }
+
+    pub fn register_error_callback<E>(&mut self, callback: Box<E>)
+        where E: Fn(OutStream, ffi::SioError) + 'a
+    {
+        self.callbacks.error = Some(callback);
+        unsafe {
+            (*self.view.stream).error_callback = Some(error_wrapper::<E>);
+        }
+    }
+}
+impl<'a> ::std::ops::Deref for OutStreamHandle<'a> {
+    type Target = OutStream<'a>;
+    fn deref(&self) -> &OutStream<'a> {
+        &self.view
+    }
+}
+impl<'a> Drop for OutStreamHandle<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::soundio_outstream_destroy(self.view.stream) }
+    }
+}
+
+/// Commits the write (via `soundio_outstream_end_write`) on drop.
+pub struct WriteGuard<'s> {
+    stream: *mut ffi::SoundIoOutStream,
+    areas: *mut ffi::SoundIoChannelArea,
+    channel_count: usize,
+    frame_count: i32,
+    _marker: ::std::marker::PhantomData<&'s ()>,
+}
+impl<'s> WriteGuard<'s> {
+    pub fn frame_count(&self) -> i32 {
+        self.frame_count
+    }
+
+    // writes one sample at area.ptr + area.step * idx, honoring interleaved layouts
+    pub fn set_sample(&mut self, ch: usize, idx: usize, value: f32) {
+        assert!(ch < self.channel_count);
+        assert!((idx as i32) < self.frame_count);
+        let area = unsafe { *self.areas.offset(ch as isize) };
+        let addr = (area.ptr as usize + area.step as usize * idx) as *mut f32;
+        unsafe { *addr = value }
     }
 }
-impl<'a> Drop for OutStream<'a> {
+impl<'s> Drop for WriteGuard<'s> {
     fn drop(&mut self) {
-        // TODO: call destroy manually.
-        // OutStream will get dropped each time a new
-        // struct is created from the same *mut pointer.
+        unsafe { ffi::soundio_outstream_end_write(self.stream); }
+    }
+}
+
+extern "C" fn read_wrapper<R>(raw_in: *mut ffi::SoundIoInStream, min: c_int, max: c_int)
+    where R: Fn(InStream, i32, i32)
+{
+    let inp = InStream::new(raw_in);
+    let callbacks = unsafe { &*((*raw_in).userdata as *const InStreamCallbacks) };
+    callbacks.read.as_ref().map(|ref f| f(inp, min as i32, max as i32));
+}
+
+extern "C" fn overflow_wrapper<O>(raw_in: *mut ffi::SoundIoInStream)
+    where O: Fn(InStream)
+{
+    let inp = InStream::new(raw_in);
+    let callbacks = unsafe { &*((*raw_in).userdata as *const InStreamCallbacks) };
+    callbacks.overflow.as_ref().map(|ref f| f(inp));
+}
+
+extern "C" fn in_error_wrapper<E>(raw_in: *mut ffi::SoundIoInStream, error: ffi::SioError)
+    where E: Fn(InStream, ffi::SioError)
+{
+    let inp = InStream::new(raw_in);
+    let callbacks = unsafe { &*((*raw_in).userdata as *const InStreamCallbacks) };
+    callbacks.error.as_ref().map(|ref f| f(inp, error));
+}
+
+struct InStreamCallbacks<'a> {
+    read: Option<Box<Fn(InStream, i32, i32) + 'a>>,
+    overflow: Option<Box<Fn(InStream) + 'a>>,
+    error: Option<Box<Fn(InStream, ffi::SioError) + 'a>>,
+}
+impl<'a> Default for InStreamCallbacks<'a> {
+    fn default() -> Self {
+        InStreamCallbacks {
+            read: None,
+            overflow: None,
+            error: None,
+        }
+    }
+}
+
+/// A non-owning view of an input stream, passed into callbacks. Not
+/// `Clone`/`Copy` - `InStreamHandle`'s `Deref` must only ever hand out
+/// `&InStream<'a>` borrows tied to the handle's lifetime, never an owned
+/// copy that could outlive the handle's `Drop` (which destroys the stream).
+pub struct InStream<'a> {
+    stream: *mut ffi::SoundIoInStream,
+    _marker: ::std::marker::PhantomData<&'a ()>,
+}
+impl<'a> InStream<'a> {
+    fn new(raw_stream: *mut ffi::SoundIoInStream) -> Self {
+        InStream {
+            stream: raw_stream,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+
+    pub fn open(&self) -> Option<ffi::SioError> {
+        match unsafe { ffi::soundio_instream_open(self.stream) } {
+            ffi::SioError::None => None,
+            err @ _ => Some(err),
+        }
+    }
+
+    pub fn start(&self) -> Option<ffi::SioError> {
+        match unsafe { ffi::soundio_instream_start(self.stream) } {
+            ffi::SioError::None => None,
+            err @ _ => Some(err),
+        }
+    }
+
+    /// Requests `min_frame_count` frames from the device with a single
+    /// `begin_read`/`end_read` pair, deinterleaving each channel area
+    /// (honoring `area.step`) into its own `Vec<f32>`. Each returned buffer
+    /// holds the number of frames `begin_read` granted, which can be fewer
+    /// than `min_frame_count`.
+    pub fn read_stream(&self, min_frame_count: i32) -> Result<Vec<Vec<f32>>, ffi::SioError> {
+        if try!(self.current_format()) != ffi::SioFormat::Float32 {
+            return Err(ffi::SioError::Invalid);
+        }
+
+        let channel_count = self.get_layout().channel_count();
+        let mut frame_count = min_frame_count as c_int;
+        let mut raw_areas: *mut ffi::SoundIoChannelArea = ptr::null_mut();
+        let actual_frame_count = try!(self.begin_read(&mut raw_areas, &mut frame_count));
+        let areas = unsafe { ::std::slice::from_raw_parts_mut(raw_areas, channel_count as usize) };
+        let mut buffers: Vec<Vec<f32>> = (0..channel_count)
+            .map(|_| Vec::with_capacity(actual_frame_count as usize))
+            .collect();
+        for channel in 0..channel_count as usize {
+            let area = areas[channel];
+            for idx in 0..actual_frame_count as usize {
+                let addr = (area.ptr as usize + area.step as usize * idx) as *const f32;
+                buffers[channel].push(unsafe { *addr });
+            }
+        }
+        self.end_read().map_or(Ok(buffers), |err| Err(err))
+    }
+
+    pub fn begin_read(&self,
+                      areas: *mut *mut ffi::SoundIoChannelArea,
+                      frame_count: *mut c_int)
+                      -> Result<i32, ffi::SioError> {
+        match unsafe { ffi::soundio_instream_begin_read(self.stream, areas, frame_count) } {
+            ffi::SioError::None => Ok(unsafe { *frame_count } as i32),
+            err @ _ => Err(err),
+        }
+    }
+
+    pub fn end_read(&self) -> Option<ffi::SioError> {
+        match unsafe { ffi::soundio_instream_end_read(self.stream) } {
+            ffi::SioError::None => None,
+            err @ _ => Some(err),
+        }
+    }
+
+    pub fn pause(&self, pause: bool) -> Option<ffi::SioError> {
+        let pause_c_bool = match pause {
+            true => 1u8,
+            false => 0u8,
+        };
+        match unsafe { ffi::soundio_instream_pause(self.stream, pause_c_bool) } {
+            ffi::SioError::None => None,
+            err @ _ => Some(err),
+        }
+    }
+
+    pub fn get_latency(&self) -> Result<f64, ffi::SioError> {
+        let mut latency = 0.0f64;
+        match unsafe {
+            ffi::soundio_instream_get_latency(self.stream, &mut latency as *mut c_double)
+        } {
+            ffi::SioError::None => Ok(latency),
+            err @ _ => Err(err),
+        }
+
+    }
+
+    pub fn current_format(&self) -> Result<ffi::SioFormat, ffi::SioError> {
+        match unsafe { (*self.stream).format } {
+            ffi::SioFormat::Invalid => Err(ffi::SioError::Invalid),
+            fmt @ _ => Ok(fmt),
+        }
+    }
+
+    pub fn get_layout(&self) -> ChannelLayout {
+        ChannelLayout::new(unsafe { &(*self.stream).layout })
+    }
+
+    pub fn get_sample_rate(&self) -> i32 {
+        unsafe { (*self.stream).sample_rate as i32 }
+    }
+
+    pub fn get_device(&self) -> Device {
+        let dev = Device::new(unsafe { (*self.stream).device });
+        dev.inc_ref();
+        dev
+    }
+
+}
+
+/// The owning handle for an input stream; destroys it on drop.
+pub struct InStreamHandle<'a> {
+    view: InStream<'a>,
+    callbacks: Box<InStreamCallbacks<'a>>,
+}
+impl<'a> InStreamHandle<'a> {
+    pub fn new(raw_stream: *mut ffi::SoundIoInStream) -> Self {
+        let mut callbacks = Box::new(InStreamCallbacks::default());
+        unsafe {
+            (*raw_stream).userdata = &mut *callbacks as *mut InStreamCallbacks as *mut c_void;
+        }
+        InStreamHandle {
+            view: InStream::new(raw_stream),
+            callbacks: callbacks,
+        }
+    }
+
+    pub fn register_read_callback<R>(&mut self, callback: Box<R>)
+        where R: Fn(InStream, i32, i32) + 'a
+    {
+        self.callbacks.read = Some(callback);
+        unsafe {
+            (*self.view.stream).read_callback = Some(read_wrapper::<R>);
+        }
+    }
+
+    pub fn register_overflow_callback<O>(&mut self, callback: Box<O>)
+        where O: Fn(InStream) + 'a
+    {
+        self.callbacks.overflow = Some(callback);
+        unsafe {
+            (*self.view.stream).overflow_callback = Some(overflow_wrapper::<O>);
+        }
+    }
+
+    pub fn register_error_callback<E>(&mut self, callback: Box<E>)
+        where E: Fn(InStream, ffi::SioError) + 'a
+    {
+        self.callbacks.error = Some(callback);
+        unsafe {
+            (*self.view.stream).error_callback = Some(in_error_wrapper::<E>);
+        }
+    }
+}
+impl<'a> ::std::ops::Deref for InStreamHandle<'a> {
+    type Target = InStream<'a>;
+    fn deref(&self) -> &InStream<'a> {
+        &self.view
+    }
+}
+impl<'a> Drop for InStreamHandle<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::soundio_instream_destroy(self.view.stream) }
+    }
+}
+
+// Owns the underlying libsoundio ring buffer. Shared between `Producer` and
+// `Consumer` via `Arc` so the buffer is destroyed once both halves are
+// dropped; never exposed on its own, since `&RawRingBuffer` gives no
+// indication of which side (write or read) is safe to call.
+struct RawRingBuffer {
+    ring_buffer: *mut ffi::SoundIoRingBuffer,
+}
+// Safe to send: the underlying libsoundio ring buffer owns its storage and
+// has no thread-affinity. Deliberately *not* Sync - see `Producer`/`Consumer`.
+unsafe impl Send for RawRingBuffer {}
+impl Drop for RawRingBuffer {
+    fn drop(&mut self) {
+        unsafe { ffi::soundio_ring_buffer_destroy(self.ring_buffer) }
+    }
+}
+
+/// Creates a lock-free single-producer/single-consumer ring buffer, split
+/// into its write half (`Producer`) and read half (`Consumer`). Neither
+/// handle is `Clone`, so the SPSC discipline libsoundio's ring buffer
+/// requires is enforced by the type system: each half can move to its own
+/// thread (`Send`), but can't be shared (`!Sync`), so only one thread at a
+/// time can ever call `write_slice` and only one can ever call `read_slice`.
+pub fn ring_buffer(sio: &SoundIo, capacity: i32) -> Result<(Producer, Consumer), ffi::SioError> {
+    let raw = unsafe { ffi::soundio_ring_buffer_create(sio.as_ptr(), capacity as c_int) };
+    if raw.is_null() {
+        return Err(ffi::SioError::NoMem);
+    }
+    // RawRingBuffer is intentionally !Sync (see above); Producer/Consumer
+    // assert their own Send-ness manually instead of relying on Arc's.
+    #[allow(unknown_lints, clippy::arc_with_non_send_sync)]
+    let inner = Arc::new(RawRingBuffer { ring_buffer: raw });
+    Ok((Producer { inner: inner.clone() }, Consumer { inner: inner }))
+}
+
+/// The write half of a ring buffer created by `ring_buffer`.
+pub struct Producer {
+    inner: Arc<RawRingBuffer>,
+}
+// Send but deliberately not Sync: write_slice/advance_write_ptr take &self,
+// and libsoundio only allows one writer thread at a time.
+unsafe impl Send for Producer {}
+impl Producer {
+    pub fn capacity(&self) -> i32 {
+        unsafe { ffi::soundio_ring_buffer_capacity(self.inner.ring_buffer) as i32 }
+    }
+
+    /// Number of bytes free for writing.
+    pub fn free_count(&self) -> i32 {
+        unsafe { ffi::soundio_ring_buffer_free_count(self.inner.ring_buffer) as i32 }
+    }
+
+    /// Copies `data` into the write pointer and advances it.
+    pub fn write_slice(&self, data: &[f32]) -> Result<(), ffi::SioError> {
+        let byte_len = data.len() * ::std::mem::size_of::<f32>();
+        if byte_len > self.free_count() as usize {
+            return Err(ffi::SioError::Invalid);
+        }
+        let dst = unsafe { ffi::soundio_ring_buffer_write_ptr(self.inner.ring_buffer) } as *mut f32;
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len()) };
+        self.advance_write_ptr(byte_len as i32);
+        Ok(())
+    }
+
+    pub fn advance_write_ptr(&self, count: i32) {
+        unsafe { ffi::soundio_ring_buffer_advance_write_ptr(self.inner.ring_buffer, count as c_int) }
+    }
+}
+
+/// The read half of a ring buffer created by `ring_buffer`.
+pub struct Consumer {
+    inner: Arc<RawRingBuffer>,
+}
+// Send but deliberately not Sync: read_slice/advance_read_ptr take &self,
+// and libsoundio only allows one reader thread at a time.
+unsafe impl Send for Consumer {}
+impl Consumer {
+    pub fn capacity(&self) -> i32 {
+        unsafe { ffi::soundio_ring_buffer_capacity(self.inner.ring_buffer) as i32 }
+    }
+
+    /// Number of bytes ready to be read.
+    pub fn fill_count(&self) -> i32 {
+        unsafe { ffi::soundio_ring_buffer_fill_count(self.inner.ring_buffer) as i32 }
+    }
+
+    pub fn clear(&self) {
+        unsafe { ffi::soundio_ring_buffer_clear(self.inner.ring_buffer) }
+    }
+
+    /// Copies from the read pointer into `data` and advances it.
+    pub fn read_slice(&self, data: &mut [f32]) -> Result<(), ffi::SioError> {
+        let byte_len = data.len() * ::std::mem::size_of::<f32>();
+        if byte_len > self.fill_count() as usize {
+            return Err(ffi::SioError::Invalid);
+        }
+        let src = unsafe { ffi::soundio_ring_buffer_read_ptr(self.inner.ring_buffer) } as *const f32;
+        unsafe { ptr::copy_nonoverlapping(src, data.as_mut_ptr(), data.len()) };
+        self.advance_read_ptr(byte_len as i32);
+        Ok(())
+    }
+
+    pub fn advance_read_ptr(&self, count: i32) {
+        unsafe { ffi::soundio_ring_buffer_advance_read_ptr(self.inner.ring_buffer, count as c_int) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // No real device/backend is available in this test environment, so this
+    // stubs out a SoundIoInStream by hand to exercise read_stream's format
+    // check without going through begin_read/end_read.
+    #[test]
+    fn read_stream_rejects_non_float32_format() {
+        let mut raw: ffi::SoundIoInStream = unsafe { ::std::mem::zeroed() };
+        raw.format = ffi::SioFormat::S16;
+        let instream = InStream::new(&mut raw as *mut _);
+        assert!(instream.read_stream(64).is_err());
+    }
+
+    // Stubs out a WriteGuard by hand, same style as
+    // read_stream_rejects_non_float32_format, to pin down that set_sample
+    // rejects out-of-range ch/idx with a panic instead of writing outside
+    // the granted areas. The guard's Drop calls into real libsoundio with `stream`, so these
+    // use catch_unwind + forget to check the panic without letting Drop run
+    // against the dummy null pointer.
+    fn dummy_write_guard<'s>(areas: &'s mut [ffi::SoundIoChannelArea]) -> WriteGuard<'s> {
+        WriteGuard {
+            stream: ptr::null_mut(),
+            areas: areas.as_mut_ptr(),
+            channel_count: 1,
+            frame_count: 4,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+
+    #[test]
+    fn set_sample_panics_on_out_of_range_channel() {
+        let mut areas = [ffi::SoundIoChannelArea { ptr: ptr::null_mut(), step: 0 }];
+        let mut guard = dummy_write_guard(&mut areas);
+        let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+            guard.set_sample(1, 0, 0.0);
+        }));
+        assert!(result.is_err());
+        ::std::mem::forget(guard);
+    }
+
+    #[test]
+    fn set_sample_panics_on_out_of_range_index() {
+        let mut areas = [ffi::SoundIoChannelArea { ptr: ptr::null_mut(), step: 0 }];
+        let mut guard = dummy_write_guard(&mut areas);
+        let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+            guard.set_sample(0, 4, 0.0);
+        }));
+        assert!(result.is_err());
+        ::std::mem::forget(guard);
+    }
+
+    #[test]
+    fn producer_consumer_ring_buffer() {
+        let sio = SoundIo::new();
+        let (producer, consumer) = ring_buffer(&sio, 4096).unwrap();
+
+        let produced: Vec<f32> = (0..64).map(|i| i as f32).collect();
+        let to_write = produced.clone();
+        producer.write_slice(&to_write).unwrap();
+
+        let mut consumed = vec![0.0f32; 64];
+        consumer.read_slice(&mut consumed).unwrap();
+
+        assert_eq!(produced, consumed);
+        assert_eq!(consumer.fill_count(), 0);
+
+        let mut oversized = vec![0.0f32; 4096];
+        assert!(consumer.read_slice(&mut oversized).is_err());
+    }
+
+    // In real use, a producer thread keeps calling write_slice() while the
+    // registered write callback drains exactly actual_frame_count frames
+    // from the ring buffer on the audio thread - no lock needed on either
+    // side, as long as there is exactly one writer and one reader. This
+    // test exercises that by racing a live writer thread against the read
+    // on the main thread, with the Producer moved onto its own thread and
+    // the Consumer kept on this one - exactly the split the type system
+    // now enforces.
+    #[test]
+    fn producer_consumer_ring_buffer_concurrent() {
+        let sio = SoundIo::new();
+        let (producer, consumer) = ring_buffer(&sio, 4096).unwrap();
+
+        let frame_count = 1000;
+        let writer = thread::spawn(move || {
+            for i in 0..frame_count {
+                let sample = [i as f32];
+                while producer.write_slice(&sample).is_err() {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut consumed = Vec::with_capacity(frame_count);
+        while consumed.len() < frame_count {
+            let mut sample = [0.0f32];
+            if consumer.read_slice(&mut sample).is_ok() {
+                consumed.push(sample[0]);
+            } else {
+                thread::yield_now();
+            }
+        }
+        writer.join().unwrap();
+
+        let expected: Vec<f32> = (0..frame_count).map(|i| i as f32).collect();
+        assert_eq!(consumed, expected);
     }
 }
\ No newline at end of file